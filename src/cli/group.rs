@@ -3,8 +3,10 @@
 use anyhow::{bail, Result};
 use clap::{Args, Subcommand};
 use serde::Serialize;
+use std::io::Write;
 
 use crate::session::{GroupTree, Storage};
+use crate::util::fuzzy;
 
 #[derive(Subcommand)]
 pub enum GroupCommands {
@@ -50,11 +52,16 @@ pub struct GroupDeleteArgs {
 
 #[derive(Args)]
 pub struct GroupMoveArgs {
-    /// Session ID or title
+    /// Session ID or title (fuzzy-matched if no exact/prefix match is found)
     identifier: String,
 
-    /// Target group
+    /// Target group (fuzzy-matched against existing groups)
     group: String,
+
+    /// Skip the "did you mean?" confirmation prompt when the top match
+    /// clearly outscores the runner-up (a close call still prompts)
+    #[arg(long)]
+    yes: bool,
 }
 
 #[derive(Serialize)]
@@ -133,6 +140,13 @@ async fn create_group(profile: &str, args: GroupCreateArgs) -> Result<()> {
         args.name.clone()
     };
 
+    if Storage::is_reserved_group_path(&group_path) {
+        bail!(
+            "Group name '{}' is reserved and cannot be used",
+            group_path
+        );
+    }
+
     let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
 
     if group_tree.group_exists(&group_path) {
@@ -140,7 +154,15 @@ async fn create_group(profile: &str, args: GroupCreateArgs) -> Result<()> {
     }
 
     group_tree.create_group(&group_path);
-    storage.save_with_groups(&instances, &group_tree)?;
+
+    // Only the newly created group's metadata (and that of any missing
+    // parent groups it implied) is new; every other group and all session
+    // state are untouched.
+    for group in group_tree.get_all_groups() {
+        if !groups.iter().any(|g| g.path == group.path) {
+            storage.save_group_metadata(&group)?;
+        }
+    }
 
     println!("✓ Created group: {}", group_path);
 
@@ -157,35 +179,51 @@ async fn delete_group(profile: &str, args: GroupDeleteArgs) -> Result<()> {
         bail!("Group not found: {}", args.name);
     }
 
-    // Check for sessions in this group
-    let session_count = instances
+    // Sessions in this group (and its nested subgroups), force-moved to
+    // the default group below if requested.
+    let moved_ids: Vec<String> = instances
         .iter()
         .filter(|i| {
             i.group_path == args.name || i.group_path.starts_with(&format!("{}/", args.name))
         })
-        .count();
-
-    if session_count > 0 {
-        if !args.force {
-            bail!(
-                "Group '{}' contains {} sessions. Use --force to move them to default group.",
-                args.name,
-                session_count
-            );
-        }
+        .map(|i| i.id.clone())
+        .collect();
+    let session_count = moved_ids.len();
+
+    if session_count > 0 && !args.force {
+        bail!(
+            "Group '{}' contains {} sessions. Use --force to move them to default group.",
+            args.name,
+            session_count
+        );
+    }
 
-        // Move sessions to default group
-        for inst in &mut instances {
-            if inst.group_path == args.name
-                || inst.group_path.starts_with(&format!("{}/", args.name))
-            {
-                inst.group_path = String::new();
-            }
+    for inst in &mut instances {
+        if moved_ids.contains(&inst.id) {
+            inst.group_path = String::new();
         }
     }
 
     group_tree.delete_group(&args.name);
-    storage.save_with_groups(&instances, &group_tree)?;
+
+    // Only the deleted group's own directory (and its nested subgroups)
+    // is removed; every other group's files are untouched. Force-moved
+    // sessions are merged into the root group's *on-disk* session file
+    // rather than rewritten from whatever else happens to be in memory
+    // for the root group, so this write can never orphan or duplicate an
+    // unrelated ungrouped session.
+    storage.remove_group_dir(&args.name)?;
+    if args.force && session_count > 0 {
+        let mut root_members = storage.read_group_sessions("")?;
+        root_members.retain(|i| !moved_ids.contains(&i.id));
+        root_members.extend(
+            instances
+                .iter()
+                .filter(|i| moved_ids.contains(&i.id))
+                .cloned(),
+        );
+        storage.save_group_sessions("", &root_members)?;
+    }
 
     println!("✓ Deleted group: {}", args.name);
     if args.force && session_count > 0 {
@@ -199,30 +237,153 @@ async fn move_session(profile: &str, args: GroupMoveArgs) -> Result<()> {
     let storage = Storage::new(profile)?;
     let (mut instances, groups) = storage.load_with_groups()?;
 
-    let inst = instances
-        .iter_mut()
-        .find(|i| {
-            i.id == args.identifier
-                || i.id.starts_with(&args.identifier)
-                || i.title == args.identifier
-        })
-        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", args.identifier))?;
+    let exact_idx = instances.iter().position(|i| {
+        i.id == args.identifier || i.id.starts_with(&args.identifier) || i.title == args.identifier
+    });
+
+    let idx = match exact_idx {
+        Some(idx) => idx,
+        None => resolve_session_index(&args.identifier, &instances, args.yes)?,
+    };
 
+    let group_tree = GroupTree::new_with_groups(&instances, &groups);
+    let target_group = resolve_group_path(&args.group, &group_tree, args.yes)?;
+
+    if Storage::is_reserved_group_path(&target_group) {
+        bail!(
+            "Group name '{}' is reserved and cannot be used",
+            target_group
+        );
+    }
+
+    let inst = &mut instances[idx];
     let old_group = inst.group_path.clone();
-    inst.group_path = args.group.clone();
+    let moved_id = inst.id.clone();
+    inst.group_path = target_group.clone();
+    let moved_instance = instances[idx].clone();
 
     let mut group_tree = GroupTree::new_with_groups(&instances, &groups);
-    if !args.group.is_empty() {
-        group_tree.create_group(&args.group);
+    if !target_group.is_empty() && !group_tree.group_exists(&target_group) {
+        group_tree.create_group(&target_group);
+        storage.save_group_metadata(
+            group_tree
+                .get_all_groups()
+                .iter()
+                .find(|g| g.path == target_group)
+                .expect("group_tree.create_group just inserted this path"),
+        )?;
     }
 
-    storage.save_with_groups(&instances, &group_tree)?;
+    // Only the source and destination groups' session-state files change;
+    // the rest of groups.d is untouched. Each is read-modify-written from
+    // its *on-disk* state plus just the moved session, rather than
+    // rewritten from whatever else happens to be in memory for that
+    // group, so this can never orphan or duplicate a session belonging to
+    // either group.
+    let mut old_members = storage.read_group_sessions(&old_group)?;
+    old_members.retain(|i| i.id != moved_id);
+    storage.save_group_sessions(&old_group, &old_members)?;
+
+    if target_group != old_group {
+        let mut new_members = storage.read_group_sessions(&target_group)?;
+        new_members.retain(|i| i.id != moved_id);
+        new_members.push(moved_instance);
+        storage.save_group_sessions(&target_group, &new_members)?;
+    }
 
     if old_group.is_empty() {
-        println!("✓ Moved session to group: {}", args.group);
+        println!("✓ Moved session to group: {}", target_group);
     } else {
-        println!("✓ Moved session from '{}' to '{}'", old_group, args.group);
+        println!("✓ Moved session from '{}' to '{}'", old_group, target_group);
     }
 
     Ok(())
 }
+
+/// Resolve `query` against `instances` by fuzzy subsequence match over
+/// `id + title`. Auto-accepts the top candidate only when it clearly
+/// dominates the runner-up and `auto_yes` was passed; otherwise presents a
+/// picker so the user confirms (or corrects) the guess.
+fn resolve_session_index(
+    query: &str,
+    instances: &[crate::session::Instance],
+    auto_yes: bool,
+) -> Result<usize> {
+    let labels: Vec<String> = instances
+        .iter()
+        .map(|i| format!("{} {}", i.id, i.title))
+        .collect();
+
+    let ranked = fuzzy::rank(query, &labels);
+    if ranked.is_empty() {
+        bail!("Session not found: {}", query);
+    }
+
+    if auto_yes && fuzzy::top_dominates(&ranked) {
+        return Ok(ranked[0].index);
+    }
+
+    let options: Vec<String> = ranked
+        .iter()
+        .map(|r| format!("{} ({})", instances[r.index].title, instances[r.index].id))
+        .collect();
+    let choice = pick_from_list("Multiple sessions match - pick one:", &options)?;
+    Ok(ranked[choice].index)
+}
+
+/// Resolve `query` against the existing group tree, offering a fuzzy
+/// "did you mean?" suggestion before creating a brand-new group by typo.
+fn resolve_group_path(query: &str, group_tree: &GroupTree, auto_yes: bool) -> Result<String> {
+    if query.is_empty() || group_tree.group_exists(query) {
+        return Ok(query.to_string());
+    }
+
+    let paths: Vec<String> = group_tree
+        .get_all_groups()
+        .iter()
+        .map(|g| g.path.clone())
+        .collect();
+
+    let ranked = fuzzy::rank(query, &paths);
+    if ranked.is_empty() {
+        return Ok(query.to_string());
+    }
+
+    if auto_yes && fuzzy::top_dominates(&ranked) {
+        return Ok(paths[ranked[0].index].clone());
+    }
+
+    let mut options: Vec<String> = ranked.iter().map(|r| paths[r.index].clone()).collect();
+    options.push(format!("{} (create new group)", query));
+    let choice = pick_from_list(
+        &format!("Group '{}' not found - did you mean?", query),
+        &options,
+    )?;
+
+    if choice == options.len() - 1 {
+        Ok(query.to_string())
+    } else {
+        Ok(paths[ranked[choice].index].clone())
+    }
+}
+
+/// A minimal numbered-list picker for the terminal CLI (not the TUI).
+fn pick_from_list(prompt: &str, options: &[String]) -> Result<usize> {
+    println!("{}", prompt);
+    for (idx, option) in options.iter().enumerate() {
+        println!("  {}) {}", idx + 1, option);
+    }
+    print!("> ");
+    std::io::stdout().flush()?;
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    let choice: usize = input
+        .trim()
+        .parse()
+        .ok()
+        .filter(|n| *n >= 1 && *n <= options.len())
+        .ok_or_else(|| anyhow::anyhow!("Invalid selection"))?;
+
+    Ok(choice - 1)
+}
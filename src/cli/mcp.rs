@@ -5,6 +5,7 @@ use clap::{Args, Subcommand};
 use serde::Serialize;
 
 use crate::session::{mcp, Storage};
+use crate::util::lev::suggest_closest;
 
 #[derive(Subcommand)]
 pub enum McpCommands {
@@ -19,6 +20,12 @@ pub enum McpCommands {
 
     /// Detach MCP from session
     Detach(McpDetachArgs),
+
+    /// Attach a named bundle of MCPs to a session
+    AttachBundle(McpBundleArgs),
+
+    /// Detach a named bundle of MCPs from a session
+    DetachBundle(McpBundleArgs),
 }
 
 #[derive(Args)]
@@ -72,6 +79,23 @@ pub struct McpDetachArgs {
     restart: bool,
 }
 
+#[derive(Args)]
+pub struct McpBundleArgs {
+    /// Session ID or title
+    identifier: String,
+
+    /// Bundle name
+    bundle: String,
+
+    /// Apply to global Claude config (all projects)
+    #[arg(long)]
+    global: bool,
+
+    /// Restart session after change
+    #[arg(long)]
+    restart: bool,
+}
+
 #[derive(Serialize)]
 struct McpInfo {
     name: String,
@@ -87,6 +111,8 @@ pub async fn run(profile: &str, command: McpCommands) -> Result<()> {
         McpCommands::Attached(args) => attached_mcps(profile, args).await,
         McpCommands::Attach(args) => attach_mcp(profile, args).await,
         McpCommands::Detach(args) => detach_mcp(profile, args).await,
+        McpCommands::AttachBundle(args) => attach_bundle(profile, args).await,
+        McpCommands::DetachBundle(args) => detach_bundle(profile, args).await,
     }
 }
 
@@ -143,7 +169,13 @@ async fn attached_mcps(profile: &str, args: McpAttachedArgs) -> Result<()> {
     let (instances, _) = storage.load_with_groups()?;
 
     let inst = if let Some(id) = &args.identifier {
-        super::resolve_session(id, &instances)?
+        match instances
+            .iter()
+            .find(|i| i.id == *id || i.id.starts_with(id.as_str()) || i.title == *id)
+        {
+            Some(inst) => inst,
+            None => return Err(session_not_found(id, &instances)),
+        }
     } else {
         // Auto-detect from tmux
         let current_session = std::env::var("TMUX_PANE")
@@ -166,6 +198,7 @@ async fn attached_mcps(profile: &str, args: McpAttachedArgs) -> Result<()> {
     };
 
     let attached = mcp::get_attached_mcps(&inst.project_path)?;
+    let satisfied_bundles = mcp::get_satisfied_bundles(&inst.project_path)?;
 
     if args.json {
         println!("{}", serde_json::to_string_pretty(&attached)?);
@@ -178,30 +211,51 @@ async fn attached_mcps(profile: &str, args: McpAttachedArgs) -> Result<()> {
                 println!("  • {}", name);
             }
         }
+        if !satisfied_bundles.is_empty() {
+            println!("\nBundles satisfied: {}", satisfied_bundles.join(", "));
+        }
     }
 
     Ok(())
 }
 
+/// Build a "Session not found" error, suggesting the closest title by edit
+/// distance when one is within threshold.
+fn session_not_found(identifier: &str, instances: &[crate::session::Instance]) -> anyhow::Error {
+    let titles: Vec<String> = instances.iter().map(|i| i.title.clone()).collect();
+    match suggest_closest(identifier, &titles) {
+        Some(suggestion) => {
+            anyhow::anyhow!("Session '{}' not found. Did you mean '{}'?", identifier, suggestion)
+        }
+        None => anyhow::anyhow!("Session not found: {}", identifier),
+    }
+}
+
 async fn attach_mcp(profile: &str, args: McpAttachArgs) -> Result<()> {
     let storage = Storage::new(profile)?;
-    let (mut instances, groups) = storage.load_with_groups()?;
+    let (mut instances, _groups) = storage.load_with_groups()?;
 
-    let inst = instances
-        .iter_mut()
-        .find(|i| {
-            i.id == args.identifier
-                || i.id.starts_with(&args.identifier)
-                || i.title == args.identifier
-        })
-        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", args.identifier))?;
+    let inst = match instances.iter_mut().find(|i| {
+        i.id == args.identifier || i.id.starts_with(&args.identifier) || i.title == args.identifier
+    }) {
+        Some(inst) => inst,
+        None => return Err(session_not_found(&args.identifier, &instances)),
+    };
 
     let available = mcp::get_available_mcps()?;
     if !available.contains_key(&args.mcp_name) {
+        let names: Vec<String> = available.keys().cloned().collect();
+        if let Some(suggestion) = suggest_closest(&args.mcp_name, &names) {
+            bail!(
+                "MCP '{}' not found. Did you mean '{}'?",
+                args.mcp_name,
+                suggestion
+            );
+        }
         bail!(
             "MCP '{}' not found in config.toml. Available: {}",
             args.mcp_name,
-            available.keys().cloned().collect::<Vec<_>>().join(", ")
+            names.join(", ")
         );
     }
 
@@ -218,8 +272,17 @@ async fn attach_mcp(profile: &str, args: McpAttachArgs) -> Result<()> {
 
     if args.restart {
         inst.restart()?;
-        let group_tree = crate::session::GroupTree::new_with_groups(&instances, &groups);
-        storage.save_with_groups(&instances, &group_tree)?;
+        // Only the session's own group-state file needs rewriting; route
+        // through the targeted write instead of `save_with_groups`'s full
+        // `groups.d` rewrite so a concurrent change to another group can't
+        // be clobbered.
+        let group_path = inst.group_path.clone();
+        let members: Vec<_> = instances
+            .iter()
+            .filter(|i| i.group_path == group_path)
+            .cloned()
+            .collect();
+        storage.save_group_sessions(&group_path, &members)?;
         println!("  Session restarted to load new MCP");
     }
 
@@ -228,16 +291,14 @@ async fn attach_mcp(profile: &str, args: McpAttachArgs) -> Result<()> {
 
 async fn detach_mcp(profile: &str, args: McpDetachArgs) -> Result<()> {
     let storage = Storage::new(profile)?;
-    let (mut instances, groups) = storage.load_with_groups()?;
+    let (mut instances, _groups) = storage.load_with_groups()?;
 
-    let inst = instances
-        .iter_mut()
-        .find(|i| {
-            i.id == args.identifier
-                || i.id.starts_with(&args.identifier)
-                || i.title == args.identifier
-        })
-        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", args.identifier))?;
+    let inst = match instances.iter_mut().find(|i| {
+        i.id == args.identifier || i.id.starts_with(&args.identifier) || i.title == args.identifier
+    }) {
+        Some(inst) => inst,
+        None => return Err(session_not_found(&args.identifier, &instances)),
+    };
 
     if args.global {
         mcp::detach_global_mcp(&args.mcp_name)?;
@@ -252,10 +313,123 @@ async fn detach_mcp(profile: &str, args: McpDetachArgs) -> Result<()> {
 
     if args.restart {
         inst.restart()?;
-        let group_tree = crate::session::GroupTree::new_with_groups(&instances, &groups);
-        storage.save_with_groups(&instances, &group_tree)?;
+        // Only the session's own group-state file needs rewriting; route
+        // through the targeted write instead of `save_with_groups`'s full
+        // `groups.d` rewrite so a concurrent change to another group can't
+        // be clobbered.
+        let group_path = inst.group_path.clone();
+        let members: Vec<_> = instances
+            .iter()
+            .filter(|i| i.group_path == group_path)
+            .cloned()
+            .collect();
+        storage.save_group_sessions(&group_path, &members)?;
         println!("  Session restarted to unload MCP");
     }
 
     Ok(())
 }
+
+/// Build a "Bundle not found" error, suggesting the closest bundle name by
+/// edit distance when one is within threshold.
+fn bundle_not_found(bundle: &str, available: &std::collections::HashMap<String, Vec<String>>) -> anyhow::Error {
+    let names: Vec<String> = available.keys().cloned().collect();
+    match suggest_closest(bundle, &names) {
+        Some(suggestion) => {
+            anyhow::anyhow!("Bundle '{}' not found. Did you mean '{}'?", bundle, suggestion)
+        }
+        None => anyhow::anyhow!("Bundle '{}' not found. Available: {}", bundle, names.join(", ")),
+    }
+}
+
+async fn attach_bundle(profile: &str, args: McpBundleArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let (mut instances, _groups) = storage.load_with_groups()?;
+
+    let inst = match instances.iter_mut().find(|i| {
+        i.id == args.identifier || i.id.starts_with(&args.identifier) || i.title == args.identifier
+    }) {
+        Some(inst) => inst,
+        None => return Err(session_not_found(&args.identifier, &instances)),
+    };
+
+    let available = mcp::get_available_bundles()?;
+    if !available.contains_key(&args.bundle) {
+        return Err(bundle_not_found(&args.bundle, &available));
+    }
+
+    if args.global {
+        mcp::attach_global_bundle(&args.bundle)?;
+        println!("✓ Attached bundle '{}' globally", args.bundle);
+    } else {
+        mcp::attach_local_bundle(&inst.project_path, &args.bundle)?;
+        println!(
+            "✓ Attached bundle '{}' to session '{}'",
+            args.bundle, inst.title
+        );
+    }
+
+    if args.restart {
+        inst.restart()?;
+        // Only the session's own group-state file needs rewriting; route
+        // through the targeted write instead of `save_with_groups`'s full
+        // `groups.d` rewrite so a concurrent change to another group can't
+        // be clobbered.
+        let group_path = inst.group_path.clone();
+        let members: Vec<_> = instances
+            .iter()
+            .filter(|i| i.group_path == group_path)
+            .cloned()
+            .collect();
+        storage.save_group_sessions(&group_path, &members)?;
+        println!("  Session restarted to load new MCPs");
+    }
+
+    Ok(())
+}
+
+async fn detach_bundle(profile: &str, args: McpBundleArgs) -> Result<()> {
+    let storage = Storage::new(profile)?;
+    let (mut instances, _groups) = storage.load_with_groups()?;
+
+    let inst = match instances.iter_mut().find(|i| {
+        i.id == args.identifier || i.id.starts_with(&args.identifier) || i.title == args.identifier
+    }) {
+        Some(inst) => inst,
+        None => return Err(session_not_found(&args.identifier, &instances)),
+    };
+
+    let available = mcp::get_available_bundles()?;
+    if !available.contains_key(&args.bundle) {
+        return Err(bundle_not_found(&args.bundle, &available));
+    }
+
+    if args.global {
+        mcp::detach_global_bundle(&args.bundle)?;
+        println!("✓ Detached bundle '{}' globally", args.bundle);
+    } else {
+        mcp::detach_local_bundle(&inst.project_path, &args.bundle)?;
+        println!(
+            "✓ Detached bundle '{}' from session '{}'",
+            args.bundle, inst.title
+        );
+    }
+
+    if args.restart {
+        inst.restart()?;
+        // Only the session's own group-state file needs rewriting; route
+        // through the targeted write instead of `save_with_groups`'s full
+        // `groups.d` rewrite so a concurrent change to another group can't
+        // be clobbered.
+        let group_path = inst.group_path.clone();
+        let members: Vec<_> = instances
+            .iter()
+            .filter(|i| i.group_path == group_path)
+            .cloned()
+            .collect();
+        storage.save_group_sessions(&group_path, &members)?;
+        println!("  Session restarted to unload MCPs");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+//! `agent-of-empires search` command implementation
+
+use anyhow::Result;
+use clap::Args;
+
+use crate::search;
+
+#[derive(Args)]
+pub struct SearchArgs {
+    /// Query text to search session output for
+    query: String,
+
+    /// Number of results to return
+    #[arg(long, default_value_t = 10)]
+    top_k: usize,
+
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+
+    /// Proceed even though no real embedding backend is implemented yet, so
+    /// results are ranked by lexical word-hash overlap, not semantic
+    /// similarity
+    #[arg(long)]
+    allow_lexical: bool,
+}
+
+pub async fn run(args: SearchArgs) -> Result<()> {
+    let is_placeholder = search::active_backend_is_placeholder().unwrap_or(false);
+
+    if is_placeholder && !args.allow_lexical {
+        anyhow::bail!(
+            "No real embedding backend is implemented yet (see `search::embed`), \
+             so results would only be ranked by lexical word-hash overlap, not \
+             semantic similarity. Pass --allow-lexical to run the search anyway."
+        );
+    }
+
+    let hits = search::search(&args.query, args.top_k)?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&hits)?);
+        return Ok(());
+    }
+
+    if is_placeholder {
+        println!(
+            "Note: no real embedding model or API backend is implemented yet, \
+             so these matches are ranked by lexical word-hash overlap, not \
+             semantic similarity.\n"
+        );
+    }
+
+    if hits.is_empty() {
+        println!("No matches found.");
+        return Ok(());
+    }
+
+    for hit in &hits {
+        println!("[{:.3}] {}", hit.score, hit.instance_id);
+        println!("{}\n", hit.chunk_text);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+//! `agent-of-empires template` subcommands implementation
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use serde::Serialize;
+
+use crate::session::templates::{self, TemplateUpdate};
+
+#[derive(Subcommand)]
+pub enum TemplateCommands {
+    /// List configured session templates
+    List(TemplateListArgs),
+
+    /// Create or update a session template
+    Create(TemplateCreateArgs),
+
+    /// Delete a session template
+    Delete(TemplateDeleteArgs),
+}
+
+#[derive(Args)]
+pub struct TemplateListArgs {
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+pub struct TemplateCreateArgs {
+    /// Template name
+    name: String,
+
+    /// Command to launch. Omit to leave an existing template's command
+    /// unchanged.
+    #[arg(long)]
+    command: Option<String>,
+
+    /// Group to place new sessions under. Omit to leave an existing
+    /// template's group unchanged.
+    #[arg(long)]
+    group: Option<String>,
+
+    /// Starting path pattern. Omit to leave an existing template's path
+    /// unchanged.
+    #[arg(long)]
+    path: Option<String>,
+
+    /// Comma-separated MCP server names to auto-attach. Omit to leave an
+    /// existing template's MCPs unchanged.
+    #[arg(long)]
+    mcps: Option<String>,
+}
+
+#[derive(Args)]
+pub struct TemplateDeleteArgs {
+    /// Template name
+    name: String,
+}
+
+#[derive(Serialize)]
+struct TemplateInfo {
+    name: String,
+    command: String,
+    group: String,
+    path: String,
+    mcps: Vec<String>,
+}
+
+pub async fn run(command: TemplateCommands) -> Result<()> {
+    match command {
+        TemplateCommands::List(args) => list_templates(args).await,
+        TemplateCommands::Create(args) => create_template(args).await,
+        TemplateCommands::Delete(args) => delete_template(args).await,
+    }
+}
+
+async fn list_templates(args: TemplateListArgs) -> Result<()> {
+    let templates = templates::get_templates()?;
+
+    if args.json {
+        let list: Vec<TemplateInfo> = templates
+            .iter()
+            .map(|(name, t)| TemplateInfo {
+                name: name.clone(),
+                command: t.command.clone(),
+                group: t.group.clone(),
+                path: t.path.clone(),
+                mcps: t.mcps.clone(),
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&list)?);
+        return Ok(());
+    }
+
+    if templates.is_empty() {
+        println!("No templates configured.");
+        println!("Create one with: agent-of-empires template create <name> --command claude");
+        return Ok(());
+    }
+
+    println!("Templates:\n");
+    for (name, t) in &templates {
+        println!("  {} [{}]", name, t.command);
+        if !t.group.is_empty() {
+            println!("    group: {}", t.group);
+        }
+        if !t.path.is_empty() {
+            println!("    path:  {}", t.path);
+        }
+        if !t.mcps.is_empty() {
+            println!("    mcps:  {}", t.mcps.join(", "));
+        }
+    }
+    println!("\nTotal: {} templates", templates.len());
+
+    Ok(())
+}
+
+async fn create_template(args: TemplateCreateArgs) -> Result<()> {
+    let mcps = args.mcps.map(|mcps| {
+        mcps.split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    });
+
+    templates::create_template(
+        &args.name,
+        TemplateUpdate {
+            command: args.command,
+            group: args.group,
+            path: args.path,
+            mcps,
+        },
+    )?;
+
+    println!("✓ Saved template: {}", args.name);
+
+    Ok(())
+}
+
+async fn delete_template(args: TemplateDeleteArgs) -> Result<()> {
+    templates::delete_template(&args.name)?;
+    println!("✓ Deleted template: {}", args.name);
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! Overlapping line-window chunking of captured session output.
+
+/// Split `text` into overlapping windows of `window` lines, advancing by
+/// `window - overlap` lines each step so consecutive chunks share context.
+pub fn chunk_lines(text: &str, window: usize, overlap: usize) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.is_empty() {
+        return Vec::new();
+    }
+
+    let stride = window.saturating_sub(overlap).max(1);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    while start < lines.len() {
+        let end = (start + window).min(lines.len());
+        chunks.push(lines[start..end].join("\n"));
+
+        if end == lines.len() {
+            break;
+        }
+        start += stride;
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_overlap_by_requested_amount() {
+        let text = (0..100)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let chunks = chunk_lines(&text, 40, 10);
+
+        assert_eq!(chunks[0].lines().next().unwrap(), "0");
+        assert_eq!(chunks[1].lines().next().unwrap(), "30");
+    }
+
+    #[test]
+    fn short_input_produces_single_chunk() {
+        let chunks = chunk_lines("one\ntwo\nthree", 40, 10);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0], "one\ntwo\nthree");
+    }
+
+    #[test]
+    fn empty_input_produces_no_chunks() {
+        assert!(chunk_lines("", 40, 10).is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+//! Pluggable embedding backends for the search index, configured in
+//! `config.toml` analogous to how MCP servers are configured.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::session::config::load_config;
+
+/// `[search]` section of `config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchConfig {
+    /// `"local"` (a local model path) or `"api"` (a hosted embedding API).
+    #[serde(default)]
+    pub backend: String,
+
+    /// Path to a local embedding model, when `backend = "local"`. Not yet
+    /// read: [`LocalModelBackend`] has no model-loading code, so this is
+    /// reserved for when that lands and has no effect today.
+    #[serde(default)]
+    pub model_path: Option<String>,
+
+    /// API key, when `backend = "api"`.
+    #[serde(default)]
+    pub api_key: Option<String>,
+
+    /// Embedding dimension produced by the configured backend.
+    #[serde(default = "default_dimension")]
+    pub dimension: usize,
+}
+
+fn default_dimension() -> usize {
+    384
+}
+
+pub trait EmbeddingBackend {
+    fn embed(&self, text: &str) -> Result<Vec<f32>>;
+    fn dimension(&self) -> usize;
+
+    /// Whether this backend produces real semantic embeddings or is lexical
+    /// placeholder scaffolding (see [`hash_embed`]). Callers that surface
+    /// results to the user (e.g. `search run`) should use this to warn
+    /// rather than silently passing placeholder matches off as semantic.
+    fn is_placeholder(&self) -> bool {
+        false
+    }
+}
+
+/// Stand-in backend used until a real local embedding model is wired up.
+/// `embed` returns [`hash_embed`]'s lexical placeholder vectors, *not*
+/// semantic embeddings — see [`EmbeddingBackend::is_placeholder`].
+struct LocalModelBackend {
+    dimension: usize,
+}
+
+impl EmbeddingBackend for LocalModelBackend {
+    fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        Ok(hash_embed(text, self.dimension))
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn is_placeholder(&self) -> bool {
+        true
+    }
+}
+
+/// Hosted embedding API backend. Not implemented yet: no HTTP client has
+/// been wired up, so `embed` fails rather than quietly falling back to
+/// [`hash_embed`]'s placeholder vectors. Configure `backend = "local"`
+/// until this lands.
+struct ApiBackend {
+    api_key: String,
+    #[allow(dead_code)]
+    dimension: usize,
+}
+
+impl EmbeddingBackend for ApiBackend {
+    fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+        if self.api_key.is_empty() {
+            bail!("Embedding API key is not configured");
+        }
+        bail!(
+            "The `api` embedding backend is not implemented yet (no HTTP client \
+             is wired up). Set `backend = \"local\"` in the [search] config \
+             section, or wait for API backend support to land."
+        );
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
+
+/// A deterministic, dependency-free **placeholder** embedding so the index/
+/// search plumbing is exercisable before a real model or API client is
+/// wired up: scatter a hash of each word across the vector and normalize.
+/// This is a lexical bag-of-words signal, not a semantic one — matches
+/// share hashed words, not meaning. See [`EmbeddingBackend::is_placeholder`].
+fn hash_embed(text: &str, dimension: usize) -> Vec<f32> {
+    let mut vector = vec![0.0f32; dimension];
+
+    for word in text.split_whitespace() {
+        let mut hash: u64 = 14695981039346656037; // FNV offset basis
+        for byte in word.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(1099511628211); // FNV prime
+        }
+        vector[(hash as usize) % dimension] += 1.0;
+    }
+
+    let norm: f32 = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for v in &mut vector {
+            *v /= norm;
+        }
+    }
+
+    vector
+}
+
+pub fn configured_backend() -> Result<Box<dyn EmbeddingBackend>> {
+    let config = load_config()?.unwrap_or_default();
+    let search = config.search;
+
+    match search.backend.as_str() {
+        "api" => Ok(Box::new(ApiBackend {
+            api_key: search.api_key.unwrap_or_default(),
+            dimension: search.dimension,
+        })),
+        _ => Ok(Box::new(LocalModelBackend {
+            dimension: search.dimension,
+        })),
+    }
+}
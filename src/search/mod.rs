@@ -0,0 +1,71 @@
+//! Semantic search over captured session output.
+//!
+//! Each instance's tmux scrollback is periodically captured, split into
+//! overlapping line-window chunks, embedded, and persisted to a SQLite
+//! store. [`search`] embeds a query and ranks stored chunks by cosine
+//! similarity.
+
+mod chunker;
+mod embed;
+mod store;
+
+pub use embed::EmbeddingBackend;
+pub use store::SearchHit;
+
+use anyhow::Result;
+
+use crate::session::Instance;
+
+const CHUNK_WINDOW: usize = 40;
+const CHUNK_OVERLAP: usize = 10;
+
+/// Capture `instance`'s current output, chunk it, embed any new or changed
+/// chunks, and persist them. Chunks whose text hash is unchanged since the
+/// last capture are skipped so repeated polling doesn't bloat the store.
+pub fn index_instance(instance: &Instance) -> Result<()> {
+    const MAX_SCROLLBACK_LINES: usize = 5000;
+
+    let Some(output) = instance
+        .capture_output(MAX_SCROLLBACK_LINES)
+        .ok()
+        .filter(|s| !s.is_empty())
+    else {
+        return Ok(());
+    };
+
+    let backend = embed::configured_backend()?;
+    let store = store::Store::open()?;
+
+    for chunk in chunker::chunk_lines(&output, CHUNK_WINDOW, CHUNK_OVERLAP) {
+        let hash = store::text_hash(&chunk);
+
+        if store.is_up_to_date(&instance.id, hash, backend.dimension())? {
+            continue;
+        }
+
+        let vector = backend.embed(&chunk)?;
+        store.upsert_chunk(&instance.id, &chunk, hash, backend.dimension(), &vector)?;
+    }
+
+    Ok(())
+}
+
+/// Embed `query` and return the top `k` chunks across all instances,
+/// ranked by cosine similarity. Rows whose stored embedding dimension
+/// doesn't match the current model are skipped rather than scored against
+/// incompatible vectors.
+pub fn search(query: &str, top_k: usize) -> Result<Vec<SearchHit>> {
+    let backend = embed::configured_backend()?;
+    let query_vector = backend.embed(query)?;
+
+    let store = store::Store::open()?;
+    store.search(&query_vector, backend.dimension(), top_k)
+}
+
+/// Whether the currently configured embedding backend is lexical
+/// placeholder scaffolding rather than a real semantic model, so callers
+/// that print results (e.g. `search run`) can warn the user instead of
+/// passing placeholder matches off as semantic search.
+pub fn active_backend_is_placeholder() -> Result<bool> {
+    Ok(embed::configured_backend()?.is_placeholder())
+}
@@ -0,0 +1,150 @@
+//! SQLite-backed store for `(instance_id, chunk_text, vector)` rows.
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::path::PathBuf;
+
+pub struct Store {
+    conn: Connection,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub instance_id: String,
+    pub chunk_text: String,
+    pub score: f32,
+}
+
+impl Store {
+    pub fn open() -> Result<Self> {
+        let path = store_path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path)?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS chunks (
+                id INTEGER PRIMARY KEY,
+                instance_id TEXT NOT NULL,
+                chunk_text TEXT NOT NULL,
+                chunk_hash INTEGER NOT NULL,
+                dimension INTEGER NOT NULL,
+                vector BLOB NOT NULL,
+                UNIQUE(instance_id, chunk_hash)
+            )",
+            [],
+        )?;
+
+        Ok(Self { conn })
+    }
+
+    /// Whether a chunk with this hash is already indexed for `instance_id`
+    /// under the current embedding `dimension` (so a repeated capture of
+    /// unchanged output is a no-op). A row whose stored dimension differs
+    /// from `dimension` (the embedding backend/model changed) is treated
+    /// as stale rather than current, so it gets re-embedded instead of
+    /// silently staying invisible to `search`'s `WHERE dimension = ?`
+    /// filter forever.
+    pub fn is_up_to_date(&self, instance_id: &str, chunk_hash: u64, dimension: usize) -> Result<bool> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM chunks WHERE instance_id = ?1 AND chunk_hash = ?2 AND dimension = ?3)",
+            params![instance_id, chunk_hash as i64, dimension as i64],
+            |row| row.get(0),
+        )?;
+        Ok(exists)
+    }
+
+    pub fn upsert_chunk(
+        &self,
+        instance_id: &str,
+        chunk_text: &str,
+        chunk_hash: u64,
+        dimension: usize,
+        vector: &[f32],
+    ) -> Result<()> {
+        let bytes: Vec<u8> = vector.iter().flat_map(|v| v.to_le_bytes()).collect();
+
+        self.conn.execute(
+            "INSERT INTO chunks (instance_id, chunk_text, chunk_hash, dimension, vector)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(instance_id, chunk_hash)
+             DO UPDATE SET chunk_text = excluded.chunk_text,
+                           dimension = excluded.dimension,
+                           vector = excluded.vector",
+            params![instance_id, chunk_text, chunk_hash as i64, dimension as i64, bytes],
+        )?;
+
+        Ok(())
+    }
+
+    /// Rank every stored chunk whose embedding dimension matches
+    /// `dimension` by cosine similarity against `query_vector`, returning
+    /// the top `top_k`.
+    pub fn search(&self, query_vector: &[f32], dimension: usize, top_k: usize) -> Result<Vec<SearchHit>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT instance_id, chunk_text, vector FROM chunks WHERE dimension = ?1",
+        )?;
+
+        let rows = stmt.query_map(params![dimension as i64], |row| {
+            let instance_id: String = row.get(0)?;
+            let chunk_text: String = row.get(1)?;
+            let bytes: Vec<u8> = row.get(2)?;
+            Ok((instance_id, chunk_text, bytes))
+        })?;
+
+        let query_norm = normalize(query_vector);
+
+        let mut hits: Vec<SearchHit> = Vec::new();
+        for row in rows {
+            let (instance_id, chunk_text, bytes) = row?;
+            let vector = decode_vector(&bytes);
+            let score = cosine_similarity(&query_norm, &normalize(&vector));
+            hits.push(SearchHit {
+                instance_id,
+                chunk_text,
+                score,
+            });
+        }
+
+        hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        hits.truncate(top_k);
+
+        Ok(hits)
+    }
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+fn normalize(vector: &[f32]) -> Vec<f32> {
+    let norm: f32 = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return vector.to_vec();
+    }
+    vector.iter().map(|v| v / norm).collect()
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Hash a chunk's text so re-captures of unchanged output are skipped.
+pub fn text_hash(text: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn store_path() -> Result<PathBuf> {
+    let base = crate::session::get_claude_config_dir()
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| anyhow::anyhow!("Cannot find home directory"))?;
+    Ok(base.join("search_index.sqlite"))
+}
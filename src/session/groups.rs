@@ -1,9 +1,12 @@
 //! Group tree management
 
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use super::Instance;
+use crate::util::fuzzy;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Group {
@@ -253,6 +256,105 @@ fn flatten_group(
     }
 }
 
+/// Full display string a fuzzy query is matched against for a session.
+fn session_display(instance: &Instance) -> String {
+    format!(
+        "{} {} {}",
+        instance.title, instance.group_path, instance.tool
+    )
+}
+
+/// Like [`flatten_tree`], but narrowed to items matching `query` as a fuzzy
+/// subsequence over the session's title, group path, and tool (or a
+/// group's own path). Ancestor groups of a matching session stay visible
+/// so the tree's hierarchy is preserved. An empty query returns the
+/// unfiltered tree.
+pub fn filter_tree(group_tree: &GroupTree, instances: &[Instance], query: &str) -> Vec<Item> {
+    if query.is_empty() {
+        return flatten_tree(group_tree, instances);
+    }
+
+    let mut items = Vec::new();
+
+    let ungrouped: Vec<&Instance> = instances
+        .iter()
+        .filter(|i| i.group_path.is_empty() && fuzzy::score(query, &session_display(i)).is_some())
+        .collect();
+
+    for inst in ungrouped {
+        items.push(Item::Session {
+            id: inst.id.clone(),
+            depth: 0,
+        });
+    }
+
+    for root in group_tree.get_roots() {
+        filter_group(root, instances, &mut items, 0, group_tree, query);
+    }
+
+    items
+}
+
+fn group_subtree_matches(group: &Group, instances: &[Instance], query: &str) -> bool {
+    if fuzzy::score(query, &group.path).is_some() {
+        return true;
+    }
+
+    let has_matching_session = instances
+        .iter()
+        .any(|i| i.group_path == group.path && fuzzy::score(query, &session_display(i)).is_some());
+    if has_matching_session {
+        return true;
+    }
+
+    group
+        .children
+        .iter()
+        .any(|child| group_subtree_matches(child, instances, query))
+}
+
+fn filter_group(
+    group: &Group,
+    instances: &[Instance],
+    items: &mut Vec<Item>,
+    depth: usize,
+    tree: &GroupTree,
+    query: &str,
+) {
+    if !group_subtree_matches(group, instances, query) {
+        return;
+    }
+
+    let session_count = count_sessions_in_group(&group.path, instances, tree);
+
+    // Ancestor groups of a match stay visible (uncollapsed) regardless of
+    // their stored collapsed state, so the hierarchy leading to a match
+    // is never hidden.
+    items.push(Item::Group {
+        path: group.path.clone(),
+        name: group.name.clone(),
+        depth,
+        collapsed: false,
+        session_count,
+    });
+
+    let group_sessions: Vec<&Instance> = instances
+        .iter()
+        .filter(|i| i.group_path == group.path && fuzzy::score(query, &session_display(i)).is_some())
+        .collect();
+
+    for inst in group_sessions {
+        items.push(Item::Session {
+            id: inst.id.clone(),
+            depth: depth + 1,
+        });
+    }
+
+    for child in &group.children {
+        filter_group(child, instances, items, depth + 1, tree, query);
+    }
+}
+
 fn count_sessions_in_group(path: &str, instances: &[Instance], _tree: &GroupTree) -> usize {
     let prefix = format!("{}/", path);
     instances
@@ -261,6 +363,126 @@ fn count_sessions_in_group(path: &str, instances: &[Instance], _tree: &GroupTree
         .count()
 }
 
+/// `[auto_group]` section of `config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutoGroupConfig {
+    /// Opt-in: auto-grouping only runs when this is set.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Also prefix the derived group with the repo's parent directory
+    /// name, e.g. `work/frontend` instead of just `frontend`.
+    #[serde(default)]
+    pub include_parent_dir: bool,
+
+    /// When `include_parent_dir` is set, skip the parent prefix for repos
+    /// that are the only subdirectory of their parent (nothing to
+    /// disambiguate against).
+    #[serde(default)]
+    pub collapse_single_child: bool,
+
+    /// Strip the user's home directory when it appears as a path segment
+    /// feeding into the derived group name.
+    #[serde(default)]
+    pub strip_home_prefix: bool,
+}
+
+/// For every instance with an empty `group_path`, derive a group from its
+/// `project_path` by walking up to the nearest `.git` directory and using
+/// the repo folder name (optionally its parent) as the group. Never
+/// touches an instance that already has an explicit `group_path`, and is
+/// idempotent: the same repo opened from different subdirectories always
+/// derives the same group name, so re-running never drifts or duplicates.
+pub fn ensure_auto_groups(instances: &mut [Instance]) -> Result<()> {
+    let config = super::config::load_config()?
+        .unwrap_or_default()
+        .auto_group;
+
+    apply_auto_groups(instances, &config);
+
+    Ok(())
+}
+
+/// The actual auto-grouping pass, split out from [`ensure_auto_groups`] so
+/// it can be exercised end-to-end with an explicit config in tests without
+/// depending on the user's real `config.toml`.
+fn apply_auto_groups(instances: &mut [Instance], config: &AutoGroupConfig) {
+    if !config.enabled {
+        return;
+    }
+
+    for inst in instances.iter_mut() {
+        if !inst.group_path.is_empty() {
+            continue;
+        }
+
+        if let Some(group) = derive_auto_group(Path::new(&inst.project_path), config) {
+            inst.group_path = group;
+        }
+    }
+}
+
+fn derive_auto_group(project_path: &Path, config: &AutoGroupConfig) -> Option<String> {
+    let repo_root = find_vcs_root(project_path)?;
+    let repo_name = repo_root.file_name()?.to_string_lossy().to_string();
+
+    if !config.include_parent_dir {
+        return Some(repo_name);
+    }
+
+    if config.collapse_single_child && parent_has_single_child(&repo_root) {
+        return Some(repo_name);
+    }
+
+    let parent_name = repo_root
+        .parent()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+        .filter(|name| !config.strip_home_prefix || !is_home_dir_name(name));
+
+    match parent_name {
+        Some(parent) => Some(format!("{}/{}", parent, repo_name)),
+        None => Some(repo_name),
+    }
+}
+
+/// Walk up from `path` looking for the nearest ancestor containing a
+/// `.git` entry.
+fn find_vcs_root(path: &Path) -> Option<PathBuf> {
+    let mut current = Some(path);
+    while let Some(dir) = current {
+        if dir.join(".git").exists() {
+            return Some(dir.to_path_buf());
+        }
+        current = dir.parent();
+    }
+    None
+}
+
+/// Whether `repo_root`'s parent directory has no other subdirectories
+/// besides `repo_root` itself.
+fn parent_has_single_child(repo_root: &Path) -> bool {
+    let Some(parent) = repo_root.parent() else {
+        return true;
+    };
+
+    let Ok(entries) = std::fs::read_dir(parent) else {
+        return false;
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .count()
+        == 1
+}
+
+fn is_home_dir_name(name: &str) -> bool {
+    dirs::home_dir()
+        .and_then(|home| home.file_name().map(|n| n.to_string_lossy().to_string()))
+        .is_some_and(|home_name| home_name == name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,4 +522,84 @@ mod tests {
         // First item should be ungrouped session
         matches!(items[0], Item::Session { .. });
     }
+
+    #[test]
+    fn derive_auto_group_uses_repo_folder_name() {
+        let repo = std::env::temp_dir().join("aoe_test_derive_auto_group_repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+
+        let config = AutoGroupConfig {
+            enabled: true,
+            include_parent_dir: false,
+            collapse_single_child: false,
+            strip_home_prefix: false,
+        };
+
+        let group = derive_auto_group(&repo.join("src"), &config);
+
+        std::fs::remove_dir_all(&repo).unwrap();
+
+        assert_eq!(group, Some("aoe_test_derive_auto_group_repo".to_string()));
+    }
+
+    #[test]
+    fn derive_auto_group_returns_none_without_a_git_root() {
+        let config = AutoGroupConfig::default();
+        let lonely = std::env::temp_dir().join("aoe_test_no_such_repo_root_xyz");
+        assert_eq!(derive_auto_group(&lonely, &config), None);
+    }
+
+    #[test]
+    fn ensure_auto_groups_never_overrides_an_explicit_group() {
+        let mut inst = Instance::new("explicit", "/tmp/does-not-matter");
+        inst.group_path = "manual".to_string();
+        let before = inst.group_path.clone();
+
+        // With auto-grouping disabled (the default, since there's no
+        // config.toml in the test environment) nothing should change.
+        let mut instances = vec![inst];
+        ensure_auto_groups(&mut instances).unwrap();
+
+        assert_eq!(instances[0].group_path, before);
+    }
+
+    #[test]
+    fn apply_auto_groups_derives_group_for_ungrouped_instances_only() {
+        let repo = std::env::temp_dir().join("aoe_test_apply_auto_groups_repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+
+        let mut ungrouped = Instance::new("auto", &repo.to_string_lossy());
+        let mut explicit = Instance::new("manual", &repo.to_string_lossy());
+        explicit.group_path = "kept-as-is".to_string();
+
+        let mut instances = vec![ungrouped.clone(), explicit.clone()];
+        let config = AutoGroupConfig {
+            enabled: true,
+            include_parent_dir: false,
+            collapse_single_child: false,
+            strip_home_prefix: false,
+        };
+        apply_auto_groups(&mut instances, &config);
+
+        std::fs::remove_dir_all(&repo).unwrap();
+
+        assert_eq!(
+            instances[0].group_path,
+            "aoe_test_apply_auto_groups_repo"
+        );
+        assert_eq!(instances[1].group_path, "kept-as-is");
+
+        // Re-running is idempotent: the already-grouped instance keeps its
+        // derived group rather than drifting.
+        ungrouped.group_path = instances[0].group_path.clone();
+        let mut instances_again = vec![ungrouped, explicit];
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        apply_auto_groups(&mut instances_again, &config);
+        std::fs::remove_dir_all(&repo).unwrap();
+        assert_eq!(
+            instances_again[0].group_path,
+            "aoe_test_apply_auto_groups_repo"
+        );
+    }
 }
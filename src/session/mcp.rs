@@ -13,6 +13,12 @@ pub fn get_available_mcps() -> Result<HashMap<String, McpConfig>> {
     Ok(config.mcps)
 }
 
+/// Named groups of MCP server names that can be attached/detached together.
+pub fn get_available_bundles() -> Result<HashMap<String, Vec<String>>> {
+    let config = load_config()?.unwrap_or_default();
+    Ok(config.mcp_bundles)
+}
+
 pub fn get_attached_mcps(project_path: &str) -> Result<Vec<String>> {
     let mcp_json_path = Path::new(project_path).join(".mcp.json");
 
@@ -66,6 +72,87 @@ pub fn detach_local_mcp(project_path: &str, mcp_name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Attach every MCP in `bundle` to `project_path` in a single
+/// `write_mcp_json` call.
+pub fn attach_local_bundle(project_path: &str, bundle: &str) -> Result<()> {
+    let bundles = get_available_bundles()?;
+    let members = bundles
+        .get(bundle)
+        .ok_or_else(|| anyhow::anyhow!("Bundle not found: {}", bundle))?;
+
+    let mut attached = get_attached_mcps(project_path)?;
+    for name in members {
+        if !attached.contains(name) {
+            attached.push(name.clone());
+        }
+    }
+    write_mcp_json(Path::new(project_path), &attached)
+}
+
+/// Detach every MCP in `bundle` from `project_path` in a single
+/// `write_mcp_json` call.
+pub fn detach_local_bundle(project_path: &str, bundle: &str) -> Result<()> {
+    let bundles = get_available_bundles()?;
+    let members = bundles
+        .get(bundle)
+        .ok_or_else(|| anyhow::anyhow!("Bundle not found: {}", bundle))?;
+
+    let mut attached = get_attached_mcps(project_path)?;
+    attached.retain(|n| !members.contains(n));
+    write_mcp_json(Path::new(project_path), &attached)
+}
+
+/// Attach every MCP in `bundle` to the global Claude config in a single
+/// `save_claude_config` call.
+pub fn attach_global_bundle(bundle: &str) -> Result<()> {
+    let bundles = get_available_bundles()?;
+    let members = bundles
+        .get(bundle)
+        .ok_or_else(|| anyhow::anyhow!("Bundle not found: {}", bundle))?;
+
+    let claude_config_path = get_claude_global_config_path()?;
+    let mut claude_config = load_claude_config(&claude_config_path)?;
+
+    let available = get_available_mcps()?;
+    for name in members {
+        if let Some(config) = available.get(name) {
+            let server = mcp_config_to_server(config);
+            claude_config.mcpServers.insert(name.clone(), server);
+        }
+    }
+
+    save_claude_config(&claude_config_path, &claude_config)
+}
+
+/// Detach every MCP in `bundle` from the global Claude config in a single
+/// `save_claude_config` call.
+pub fn detach_global_bundle(bundle: &str) -> Result<()> {
+    let bundles = get_available_bundles()?;
+    let members = bundles
+        .get(bundle)
+        .ok_or_else(|| anyhow::anyhow!("Bundle not found: {}", bundle))?;
+
+    let claude_config_path = get_claude_global_config_path()?;
+    let mut claude_config = load_claude_config(&claude_config_path)?;
+    for name in members {
+        claude_config.mcpServers.remove(name);
+    }
+    save_claude_config(&claude_config_path, &claude_config)
+}
+
+/// Which configured bundles are fully satisfied (all members attached) for
+/// `project_path`.
+pub fn get_satisfied_bundles(project_path: &str) -> Result<Vec<String>> {
+    let bundles = get_available_bundles()?;
+    let attached = get_attached_mcps(project_path)?;
+
+    Ok(bundles
+        .into_iter()
+        .filter(|(_, members)| members.iter().all(|m| attached.contains(m)))
+        .map(|(name, _)| name)
+        .collect())
+}
+
 pub fn attach_global_mcp(mcp_name: &str) -> Result<()> {
     let claude_config_path = get_claude_global_config_path()?;
     let mut claude_config = load_claude_config(&claude_config_path)?;
@@ -0,0 +1,334 @@
+//! Profile persistence.
+//!
+//! Historically everything round-tripped through a single `sessions.json`
+//! file via [`Storage::load_with_groups`]/[`Storage::save_with_groups`],
+//! which meant any session's runtime state change rewrote the whole group
+//! tree. This module migrates profiles to a `groups.d/` directory layout:
+//! each group is a subdirectory (nested for `/`-delimited subgroups) holding
+//! its metadata (`group.json`) separate from its sessions' mutable state
+//! (`sessions.json`), plus a `groups.d/_root/sessions.json` for ungrouped
+//! sessions. That way [`Storage::save_group_sessions`] only touches the one
+//! directory whose sessions actually changed.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use super::{Group, GroupTree, Instance};
+
+const ROOT_GROUP_DIR: &str = "_root";
+
+pub struct Storage {
+    root: PathBuf,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LegacyFile {
+    #[serde(default)]
+    instances: Vec<Instance>,
+    #[serde(default)]
+    groups: Vec<Group>,
+}
+
+impl Storage {
+    pub fn new(profile: &str) -> Result<Self> {
+        let root = profile_dir(profile)?;
+        fs::create_dir_all(&root)?;
+
+        let storage = Self { root };
+        storage.migrate_legacy_if_needed()?;
+        Ok(storage)
+    }
+
+    fn legacy_path(&self) -> PathBuf {
+        self.root.join("sessions.json")
+    }
+
+    fn groups_dir(&self) -> PathBuf {
+        self.root.join("groups.d")
+    }
+
+    /// Whether `group_path` collides with (or would be nested under) the
+    /// reserved root-group directory name, which would otherwise clobber
+    /// ungrouped-session storage. Checked by `group create` before a group
+    /// is created.
+    pub fn is_reserved_group_path(group_path: &str) -> bool {
+        group_path == ROOT_GROUP_DIR || group_path.starts_with(&format!("{}/", ROOT_GROUP_DIR))
+    }
+
+    /// Directory for a given `/`-delimited group path (`""` for the root,
+    /// ungrouped sessions).
+    fn group_dir(&self, group_path: &str) -> PathBuf {
+        if group_path.is_empty() {
+            return self.groups_dir().join(ROOT_GROUP_DIR);
+        }
+        let mut dir = self.groups_dir();
+        for segment in group_path.split('/') {
+            dir = dir.join(segment);
+        }
+        dir
+    }
+
+    /// Transparently migrate an existing single-file profile into the
+    /// `groups.d/` layout the first time it's loaded.
+    fn migrate_legacy_if_needed(&self) -> Result<()> {
+        if self.groups_dir().exists() {
+            return Ok(());
+        }
+
+        let legacy = self.legacy_path();
+        let (instances, groups) = if legacy.exists() {
+            let content = fs::read_to_string(&legacy)?;
+            let file: LegacyFile = serde_json::from_str(&content)?;
+            (file.instances, file.groups)
+        } else {
+            (Vec::new(), Vec::new())
+        };
+
+        let group_tree = GroupTree::new_with_groups(&instances, &groups);
+        self.write_all(&instances, &group_tree)?;
+
+        Ok(())
+    }
+
+    /// Load every group's metadata and session state from `groups.d/`,
+    /// then run the opt-in auto-grouping pass over whatever instances are
+    /// still ungrouped, persisting any reassignment it makes before
+    /// returning (see [`Storage::apply_and_persist_auto_groups`]).
+    pub fn load_with_groups(&self) -> Result<(Vec<Instance>, Vec<Group>)> {
+        let mut instances = Vec::new();
+        let mut groups = Vec::new();
+
+        if self.groups_dir().exists() {
+            self.collect_dir(&self.groups_dir(), &mut instances, &mut groups)?;
+        }
+
+        self.apply_and_persist_auto_groups(&mut instances, &mut groups)?;
+
+        Ok((instances, groups))
+    }
+
+    /// Run the opt-in auto-grouping pass and, for any instance it actually
+    /// reassigns, immediately persist the move: rewrite `_root`'s
+    /// session-state file (it just lost that instance), register the
+    /// derived group's metadata if the group is new, and rewrite the
+    /// derived group's session-state file.
+    ///
+    /// This must happen before `load_with_groups` returns, because every
+    /// targeted writer (`save_group_sessions`/`remove_group_dir`, used by
+    /// `group move`/`group delete` and MCP `--restart`) assumes the
+    /// instances it got back from `load_with_groups` are exactly the
+    /// on-disk set for their group. If the auto-group pass only mutated
+    /// `group_path` in memory, a later targeted write to `_root` or to the
+    /// derived group would silently drop or duplicate sessions that were
+    /// never actually written to the group they now believe they're in.
+    fn apply_and_persist_auto_groups(
+        &self,
+        instances: &mut Vec<Instance>,
+        groups: &mut Vec<Group>,
+    ) -> Result<()> {
+        let before: Vec<String> = instances.iter().map(|i| i.group_path.clone()).collect();
+        super::groups::ensure_auto_groups(instances)?;
+
+        let moved_to: std::collections::BTreeSet<String> = instances
+            .iter()
+            .zip(&before)
+            .filter(|(inst, prev)| inst.group_path != **prev)
+            .map(|(inst, _)| inst.group_path.clone())
+            .collect();
+
+        if moved_to.is_empty() {
+            return Ok(());
+        }
+
+        let mut group_tree = GroupTree::new_with_groups(instances, groups);
+        for path in &moved_to {
+            if !group_tree.group_exists(path) {
+                group_tree.create_group(path);
+            }
+        }
+        for group in group_tree.get_all_groups() {
+            if !groups.iter().any(|g| g.path == group.path) {
+                self.save_group_metadata(&group)?;
+                groups.push(group);
+            }
+        }
+
+        let root_members: Vec<Instance> = instances
+            .iter()
+            .filter(|i| i.group_path.is_empty())
+            .cloned()
+            .collect();
+        self.save_group_sessions("", &root_members)?;
+
+        for path in &moved_to {
+            let members: Vec<Instance> = instances
+                .iter()
+                .filter(|i| &i.group_path == path)
+                .cloned()
+                .collect();
+            self.save_group_sessions(path, &members)?;
+        }
+
+        Ok(())
+    }
+
+    fn collect_dir(
+        &self,
+        dir: &std::path::Path,
+        instances: &mut Vec<Instance>,
+        groups: &mut Vec<Group>,
+    ) -> Result<()> {
+        if !dir.exists() {
+            return Ok(());
+        }
+
+        let group_json = dir.join("group.json");
+        if group_json.exists() {
+            let content = fs::read_to_string(&group_json)?;
+            groups.push(serde_json::from_str(&content)?);
+        }
+
+        let sessions_json = dir.join("sessions.json");
+        if sessions_json.exists() {
+            let content = fs::read_to_string(&sessions_json)?;
+            let mut group_instances: Vec<Instance> = serde_json::from_str(&content)?;
+            instances.append(&mut group_instances);
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                self.collect_dir(&entry.path(), instances, groups)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rewrite every group directory from scratch, the same full rewrite
+    /// that legacy migration performs. `group create`/`group delete`/
+    /// `group move` and MCP `--restart` use the targeted per-group writers
+    /// below instead.
+    pub fn save_with_groups(&self, instances: &[Instance], group_tree: &GroupTree) -> Result<()> {
+        self.write_all(instances, group_tree)
+    }
+
+    fn write_all(&self, instances: &[Instance], group_tree: &GroupTree) -> Result<()> {
+        if self.groups_dir().exists() {
+            fs::remove_dir_all(self.groups_dir())?;
+        }
+        fs::create_dir_all(self.groups_dir())?;
+
+        for group in group_tree.get_all_groups() {
+            self.save_group_metadata(&group)?;
+        }
+
+        let ungrouped: Vec<Instance> = instances
+            .iter()
+            .filter(|i| i.group_path.is_empty())
+            .cloned()
+            .collect();
+        self.save_group_sessions("", &ungrouped)?;
+
+        for group in group_tree.get_all_groups() {
+            let members: Vec<Instance> = instances
+                .iter()
+                .filter(|i| i.group_path == group.path)
+                .cloned()
+                .collect();
+            self.save_group_sessions(&group.path, &members)?;
+        }
+
+        Ok(())
+    }
+
+    /// Rewrite only the given group's metadata file.
+    pub fn save_group_metadata(&self, group: &Group) -> Result<()> {
+        let dir = self.group_dir(&group.path);
+        fs::create_dir_all(&dir)?;
+        let content = serde_json::to_string_pretty(group)?;
+        fs::write(dir.join("group.json"), content)?;
+        Ok(())
+    }
+
+    /// Remove a single group's directory, including any nested subgroup
+    /// directories under it, without touching any other group's files.
+    /// Used by `group delete` instead of rewriting the whole tree.
+    pub fn remove_group_dir(&self, group_path: &str) -> Result<()> {
+        let dir = self.group_dir(group_path);
+        if dir.exists() {
+            fs::remove_dir_all(dir)?;
+        }
+        Ok(())
+    }
+
+    /// Read a single group's session-state file directly from disk (empty
+    /// if it has none yet), bypassing whatever a caller currently has
+    /// loaded in memory. Targeted writers that only mean to touch one
+    /// group (`group move`, `group delete --force`) read-modify-write
+    /// through this rather than re-deriving the group's membership from a
+    /// possibly-stale in-memory `instances` list, so they can never
+    /// clobber a change to that group that hasn't made it into memory yet.
+    pub fn read_group_sessions(&self, group_path: &str) -> Result<Vec<Instance>> {
+        let path = self.group_dir(group_path).join("sessions.json");
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Rewrite only the given group's session-state file, so moving a
+    /// session only touches the two affected groups rather than the whole
+    /// store.
+    pub fn save_group_sessions(&self, group_path: &str, instances: &[Instance]) -> Result<()> {
+        let dir = self.group_dir(group_path);
+        fs::create_dir_all(&dir)?;
+        let content = serde_json::to_string_pretty(instances)?;
+        fs::write(dir.join("sessions.json"), content)?;
+        Ok(())
+    }
+}
+
+fn profile_dir(profile: &str) -> Result<PathBuf> {
+    let base = super::get_claude_config_dir()
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| anyhow::anyhow!("Cannot find home directory"))?;
+    Ok(base.join("profiles").join(profile))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a `Storage` rooted at a throwaway temp directory, bypassing
+    /// `Storage::new`'s real-home-directory profile resolution.
+    fn test_storage(name: &str) -> Storage {
+        let root = std::env::temp_dir().join(format!("aoe_test_storage_{}", name));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        Storage { root }
+    }
+
+    #[test]
+    fn load_with_groups_runs_the_auto_group_pass_without_crashing() {
+        let storage = test_storage("load_with_groups_auto_group");
+
+        let ungrouped = Instance::new("auto", "/tmp/aoe-test-project-does-not-exist");
+        storage.save_group_sessions("", &[ungrouped]).unwrap();
+
+        // End-to-end: load_with_groups is the real production entry point
+        // cli/group.rs and cli/mcp.rs call, and it must run the
+        // auto-grouping pass over whatever it loads rather than leaving
+        // `ensure_auto_groups` dead code. With no `[auto_group]` config in
+        // the test environment, the instance stays ungrouped.
+        let (instances, _groups) = storage.load_with_groups().unwrap();
+
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].group_path, "");
+
+        fs::remove_dir_all(&storage.root).unwrap();
+    }
+}
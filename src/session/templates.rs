@@ -0,0 +1,79 @@
+//! Session templates ("roles"): reusable defaults for the New Session dialog
+//! and the `template` CLI subcommand.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::config::{load_config, save_config};
+
+/// A named set of defaults for creating a new session.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionTemplate {
+    /// Command to launch (e.g. `claude`).
+    #[serde(default)]
+    pub command: String,
+
+    /// Group path to place the new session under.
+    #[serde(default)]
+    pub group: String,
+
+    /// Starting path, e.g. `~/projects`. Copied into the New Session
+    /// dialog as-is; no placeholders are expanded.
+    #[serde(default)]
+    pub path: String,
+
+    /// MCP server names to auto-attach via `write_mcp_json`.
+    #[serde(default)]
+    pub mcps: Vec<String>,
+}
+
+pub fn get_templates() -> Result<HashMap<String, SessionTemplate>> {
+    let config = load_config()?.unwrap_or_default();
+    Ok(config.templates)
+}
+
+pub fn get_template(name: &str) -> Result<Option<SessionTemplate>> {
+    Ok(get_templates()?.get(name).cloned())
+}
+
+/// Fields to set on a template. `None` leaves the existing template's field
+/// untouched (or falls back to the type default when creating a new
+/// template), so a second `template create` for the same name only
+/// overwrites the flags the caller actually passed.
+#[derive(Debug, Default)]
+pub struct TemplateUpdate {
+    pub command: Option<String>,
+    pub group: Option<String>,
+    pub path: Option<String>,
+    pub mcps: Option<Vec<String>>,
+}
+
+pub fn create_template(name: &str, update: TemplateUpdate) -> Result<()> {
+    let mut config = load_config()?.unwrap_or_default();
+    let mut template = config.templates.get(name).cloned().unwrap_or_default();
+
+    if let Some(command) = update.command {
+        template.command = command;
+    }
+    if let Some(group) = update.group {
+        template.group = group;
+    }
+    if let Some(path) = update.path {
+        template.path = path;
+    }
+    if let Some(mcps) = update.mcps {
+        template.mcps = mcps;
+    }
+
+    config.templates.insert(name.to_string(), template);
+    save_config(&config)
+}
+
+pub fn delete_template(name: &str) -> Result<()> {
+    let mut config = load_config()?.unwrap_or_default();
+    if config.templates.remove(name).is_none() {
+        bail!("Template not found: {}", name);
+    }
+    save_config(&config)
+}
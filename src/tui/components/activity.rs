@@ -0,0 +1,126 @@
+//! Live activity/progress indicator bar.
+//!
+//! Renders a single status line aggregating the most relevant in-flight
+//! state across all instances: a spinner while any session is `Starting`
+//! or restarting, a warning glyph with a count when any session is in
+//! `Error`, and a transient "✓ Attached MCP 'x'" message that fades after
+//! a few render ticks. Driven by a small event channel so `attach_mcp`/
+//! `detach_mcp`/`restart` can push progress (e.g. "reloading MCPs for
+//! '<title>'") instead of today's fire-and-forget `println!`s, which are
+//! invisible inside the TUI.
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::{Duration, Instant};
+
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::session::{Instance, Status};
+use crate::tui::styles::Theme;
+
+const SPINNER_FRAMES: [&str; 4] = ["⠋", "⠙", "⠹", "⠸"];
+/// Number of render ticks a transient message stays visible.
+const MESSAGE_FADE_TICKS: usize = 12;
+
+/// A progress event pushed by a long-running operation for the indicator
+/// to surface.
+#[derive(Debug, Clone)]
+pub enum ActivityEvent {
+    Message(String),
+}
+
+/// Cheap, cloneable handle for pushing events from wherever an operation
+/// (`attach_mcp`, `detach_mcp`, `inst.restart()`, ...) runs.
+#[derive(Clone)]
+pub struct ActivitySender(Sender<ActivityEvent>);
+
+impl ActivitySender {
+    pub fn send(&self, event: ActivityEvent) {
+        // The receiver only lives as long as the TUI is running; dropping
+        // an event when nothing is listening is fine.
+        let _ = self.0.send(event);
+    }
+
+    pub fn message(&self, text: impl Into<String>) {
+        self.send(ActivityEvent::Message(text.into()));
+    }
+}
+
+pub struct ActivityIndicator {
+    receiver: Receiver<ActivityEvent>,
+    spinner_tick: usize,
+    transient: Option<(String, usize)>,
+}
+
+impl ActivityIndicator {
+    pub fn new() -> (ActivitySender, Self) {
+        let (tx, rx) = channel();
+        (
+            ActivitySender(tx),
+            Self {
+                receiver: rx,
+                spinner_tick: 0,
+                transient: None,
+            },
+        )
+    }
+
+    /// Drain pending events and advance the spinner. Call once per render
+    /// tick before `render`.
+    pub fn tick(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                ActivityEvent::Message(message) => {
+                    self.transient = Some((message, self.spinner_tick));
+                }
+            }
+        }
+
+        if matches!(&self.transient, Some((_, since)) if self.spinner_tick.wrapping_sub(*since) >= MESSAGE_FADE_TICKS) {
+            self.transient = None;
+        }
+
+        self.spinner_tick = self.spinner_tick.wrapping_add(1);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, instances: &[Instance], theme: &Theme) {
+        let starting = instances
+            .iter()
+            .filter(|i| matches!(i.status, Status::Starting))
+            .count();
+        let errors = instances
+            .iter()
+            .filter(|i| matches!(i.status, Status::Error))
+            .count();
+
+        let mut spans = Vec::new();
+
+        if starting > 0 {
+            let frame_char = SPINNER_FRAMES[self.spinner_tick % SPINNER_FRAMES.len()];
+            spans.push(Span::styled(
+                format!("{} starting ({}) ", frame_char, starting),
+                Style::default().fg(theme.waiting),
+            ));
+        }
+
+        if errors > 0 {
+            spans.push(Span::styled(
+                format!("⚠ {} error{} ", errors, if errors == 1 { "" } else { "s" }),
+                Style::default().fg(theme.error),
+            ));
+        }
+
+        if let Some((message, _)) = &self.transient {
+            spans.push(Span::styled(
+                format!("✓ {}", message),
+                Style::default().fg(theme.running),
+            ));
+        }
+
+        if spans.is_empty() {
+            spans.push(Span::styled("Ready", Style::default().fg(theme.dimmed)));
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
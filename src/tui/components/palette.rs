@@ -0,0 +1,114 @@
+//! Fuzzy command-palette overlay: narrows the group/session tree in real
+//! time as the user types. The caller opens it on `/` or `Ctrl-P` and
+//! renders [`Palette::filtered_items`] in place of the normal flattened
+//! tree while it's open.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::session::groups::{filter_tree, GroupTree, Item};
+use crate::session::Instance;
+use crate::tui::styles::Theme;
+use crate::util::fuzzy;
+
+pub struct Palette {
+    query: String,
+    open: bool,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            open: false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.query.clear();
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Handle a key while the palette is open. Returns `true` if the key
+    /// was consumed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.open {
+            return false;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.close(),
+            KeyCode::Backspace => {
+                self.query.pop();
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// The tree narrowed to items matching the current query, falling back
+    /// to the full tree when the query is empty.
+    pub fn filtered_items(&self, group_tree: &GroupTree, instances: &[Instance]) -> Vec<Item> {
+        filter_tree(group_tree, instances, &self.query)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.open {
+            return;
+        }
+
+        let bar_area = Rect {
+            x: area.x,
+            y: area.y,
+            width: area.width,
+            height: 1,
+        };
+
+        let line = Line::from(vec![
+            Span::styled("/ ", Style::default().fg(theme.search).bold()),
+            Span::styled(&self.query, Style::default().fg(theme.text)),
+            Span::styled("█", Style::default().fg(theme.accent)),
+        ]);
+
+        frame.render_widget(Paragraph::new(line), bar_area);
+    }
+
+    /// Render `label` with its matched characters highlighted, for use in
+    /// the caller's own list rendering.
+    pub fn highlighted_line<'a>(&self, label: &'a str, theme: &Theme) -> Line<'a> {
+        let Some((_, positions)) = fuzzy::match_positions(&self.query, label) else {
+            return Line::from(Span::styled(label, Style::default().fg(theme.text)));
+        };
+
+        let mut spans = Vec::new();
+        for (idx, ch) in label.chars().enumerate() {
+            let style = if positions.contains(&idx) {
+                Style::default().fg(theme.search).bold()
+            } else {
+                Style::default().fg(theme.text)
+            };
+            spans.push(Span::styled(ch.to_string(), style));
+        }
+
+        Line::from(spans)
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,10 +1,11 @@
 //! New session dialog
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
 use super::DialogResult;
+use crate::session::templates::{get_templates, SessionTemplate};
 use crate::tui::styles::Theme;
 
 pub struct NewSessionData {
@@ -12,6 +13,9 @@ pub struct NewSessionData {
     pub path: String,
     pub group: String,
     pub command: String,
+    /// MCP server names to auto-attach, carried over from the selected
+    /// template (if any) so the caller can feed them to `write_mcp_json`.
+    pub mcps: Vec<String>,
 }
 
 pub struct NewSessionDialog {
@@ -20,6 +24,10 @@ pub struct NewSessionDialog {
     group: String,
     command: String,
     focused_field: usize,
+    templates: Vec<(String, SessionTemplate)>,
+    template_picker_open: bool,
+    template_picker_selected: usize,
+    mcps: Vec<String>,
 }
 
 impl NewSessionDialog {
@@ -28,18 +36,56 @@ impl NewSessionDialog {
             .map(|p| p.to_string_lossy().to_string())
             .unwrap_or_default();
 
+        let mut templates: Vec<(String, SessionTemplate)> =
+            get_templates().unwrap_or_default().into_iter().collect();
+        templates.sort_by(|a, b| a.0.cmp(&b.0));
+
         Self {
             title: String::new(),
             path: current_dir,
             group: String::new(),
             command: String::new(),
             focused_field: 0,
+            templates,
+            template_picker_open: false,
+            template_picker_selected: 0,
+            mcps: Vec::new(),
+        }
+    }
+
+    /// Apply a template's defaults, overwriting whatever the user has typed
+    /// so far in `path`/`group`/`command` (fields the template leaves blank
+    /// are left as-is). Selecting a template via Ctrl-T is an explicit
+    /// choice to adopt its preset, so the template wins rather than only
+    /// filling empty fields. Also remembers its MCP list to pass through on
+    /// submit.
+    fn apply_template(&mut self, template: &SessionTemplate) {
+        if !template.path.is_empty() {
+            self.path = template.path.clone();
+        }
+        if !template.group.is_empty() {
+            self.group = template.group.clone();
+        }
+        if !template.command.is_empty() {
+            self.command = template.command.clone();
         }
+        self.mcps = template.mcps.clone();
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) -> DialogResult<NewSessionData> {
+        if self.template_picker_open {
+            return self.handle_template_picker_key(key);
+        }
+
         match key.code {
             KeyCode::Esc => DialogResult::Cancel,
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if !self.templates.is_empty() {
+                    self.template_picker_open = true;
+                    self.template_picker_selected = 0;
+                }
+                DialogResult::Continue
+            }
             KeyCode::Enter => {
                 if self.title.is_empty() {
                     // Use directory name as title
@@ -53,6 +99,7 @@ impl NewSessionDialog {
                     path: self.path.clone(),
                     group: self.group.clone(),
                     command: self.command.clone(),
+                    mcps: self.mcps.clone(),
                 })
             }
             KeyCode::Tab => {
@@ -79,6 +126,31 @@ impl NewSessionDialog {
         }
     }
 
+    fn handle_template_picker_key(&mut self, key: KeyEvent) -> DialogResult<NewSessionData> {
+        match key.code {
+            KeyCode::Esc => {
+                self.template_picker_open = false;
+            }
+            KeyCode::Up => {
+                self.template_picker_selected = self.template_picker_selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                if self.template_picker_selected + 1 < self.templates.len() {
+                    self.template_picker_selected += 1;
+                }
+            }
+            KeyCode::Enter => {
+                if let Some((_, template)) = self.templates.get(self.template_picker_selected) {
+                    let template = template.clone();
+                    self.apply_template(&template);
+                }
+                self.template_picker_open = false;
+            }
+            _ => {}
+        }
+        DialogResult::Continue
+    }
+
     fn current_field_mut(&mut self) -> &mut String {
         match self.focused_field {
             0 => &mut self.title,
@@ -168,12 +240,59 @@ impl NewSessionDialog {
         let hint = Line::from(vec![
             Span::styled("Tab", Style::default().fg(theme.hint)),
             Span::raw(" next field  "),
+            Span::styled("Ctrl-T", Style::default().fg(theme.hint)),
+            Span::raw(" template  "),
             Span::styled("Enter", Style::default().fg(theme.hint)),
             Span::raw(" create  "),
             Span::styled("Esc", Style::default().fg(theme.hint)),
             Span::raw(" cancel"),
         ]);
         frame.render_widget(Paragraph::new(hint), chunks[4]);
+
+        if self.template_picker_open {
+            self.render_template_picker(frame, area, theme);
+        }
+    }
+
+    fn render_template_picker(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = 40;
+        let height = (self.templates.len() as u16 + 2).min(12);
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+
+        let picker_area = Rect {
+            x,
+            y,
+            width: width.min(area.width),
+            height: height.min(area.height),
+        };
+
+        frame.render_widget(Clear, picker_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Select Template ")
+            .title_style(Style::default().fg(theme.title).bold());
+
+        let inner = block.inner(picker_area);
+        frame.render_widget(block, picker_area);
+
+        let items: Vec<Line> = self
+            .templates
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, _))| {
+                let style = if idx == self.template_picker_selected {
+                    Style::default().fg(theme.accent).bold()
+                } else {
+                    Style::default().fg(theme.text)
+                };
+                Line::from(Span::styled(name.clone(), style))
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(items), inner);
     }
 }
 
@@ -1,6 +1,8 @@
 //! TUI theme and styling
 
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
 pub struct Theme {
@@ -55,4 +57,171 @@ impl Theme {
             accent: Color::Rgb(122, 162, 247),
         }
     }
+
+    /// A light palette for plain-background terminals.
+    pub fn day_paper() -> Self {
+        Self {
+            background: Color::Rgb(250, 250, 245),
+            border: Color::Rgb(210, 208, 200),
+            selection: Color::Rgb(230, 228, 218),
+
+            title: Color::Rgb(52, 84, 176),
+            text: Color::Rgb(40, 40, 38),
+            dimmed: Color::Rgb(140, 138, 128),
+            hint: Color::Rgb(110, 108, 98),
+
+            running: Color::Rgb(64, 140, 62),
+            waiting: Color::Rgb(180, 120, 20),
+            idle: Color::Rgb(140, 138, 128),
+            error: Color::Rgb(180, 40, 50),
+
+            group: Color::Rgb(110, 70, 160),
+            search: Color::Rgb(20, 110, 150),
+            accent: Color::Rgb(52, 84, 176),
+        }
+    }
+
+    /// Names of the built-in themes, as accepted by [`Theme::by_name`].
+    pub fn builtin_names() -> &'static [&'static str] {
+        &["tokyo_night", "day_paper"]
+    }
+
+    fn by_builtin_name(name: &str) -> Option<Self> {
+        match name {
+            "tokyo_night" => Some(Self::tokyo_night()),
+            "day_paper" => Some(Self::day_paper()),
+            _ => None,
+        }
+    }
+
+    /// Resolve a theme by name, checking user-defined palettes from the app
+    /// config before falling back to the built-in set.
+    pub fn by_name(name: &str) -> Option<Self> {
+        if let Ok(Some(registry)) = load_theme_config() {
+            if let Some(custom) = registry.themes.get(name) {
+                return Some(custom.clone().into());
+            }
+        }
+        Self::by_builtin_name(name)
+    }
+
+    /// All theme names available: built-ins plus any user-defined palettes.
+    pub fn list() -> Vec<String> {
+        let mut names: Vec<String> = Self::builtin_names().iter().map(|s| s.to_string()).collect();
+        if let Ok(Some(registry)) = load_theme_config() {
+            for name in registry.themes.keys() {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+        names
+    }
+
+    /// Pick a theme automatically: auto-detect the terminal's background
+    /// via `COLORFGBG` when present, otherwise fall back to the configured
+    /// default (or `tokyo_night` if nothing is configured).
+    pub fn auto() -> Self {
+        if let Some(light) = terminal_has_light_background() {
+            let name = if light { "day_paper" } else { "tokyo_night" };
+            if let Some(theme) = Self::by_name(name) {
+                return theme;
+            }
+        }
+
+        let default_name = load_theme_config()
+            .ok()
+            .flatten()
+            .and_then(|registry| registry.default)
+            .unwrap_or_else(|| "tokyo_night".to_string());
+
+        Self::by_name(&default_name).unwrap_or_else(Self::tokyo_night)
+    }
+}
+
+/// Parse `COLORFGBG` (format `"<fg>;<bg>"` or `"<fg>;<default>;<bg>"`) and
+/// decide whether the terminal's background is light. Returns `None` when
+/// the variable is absent or its trailing field isn't a color index.
+fn terminal_has_light_background() -> Option<bool> {
+    let value = std::env::var("COLORFGBG").ok()?;
+    let bg = value.rsplit(';').next()?;
+    let index: u8 = bg.trim().parse().ok()?;
+    Some(index == 7 || index == 15 || index >= 11)
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ThemeConfig {
+    #[serde(default)]
+    default: Option<String>,
+    #[serde(default)]
+    themes: HashMap<String, PaletteDef>,
+}
+
+/// A user-defined palette, as stored in the YAML theme config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PaletteDef {
+    background: String,
+    border: String,
+    selection: String,
+    title: String,
+    text: String,
+    dimmed: String,
+    hint: String,
+    running: String,
+    waiting: String,
+    idle: String,
+    error: String,
+    group: String,
+    search: String,
+    accent: String,
+}
+
+impl From<PaletteDef> for Theme {
+    fn from(p: PaletteDef) -> Self {
+        Self {
+            background: parse_hex_color(&p.background),
+            border: parse_hex_color(&p.border),
+            selection: parse_hex_color(&p.selection),
+            title: parse_hex_color(&p.title),
+            text: parse_hex_color(&p.text),
+            dimmed: parse_hex_color(&p.dimmed),
+            hint: parse_hex_color(&p.hint),
+            running: parse_hex_color(&p.running),
+            waiting: parse_hex_color(&p.waiting),
+            idle: parse_hex_color(&p.idle),
+            error: parse_hex_color(&p.error),
+            group: parse_hex_color(&p.group),
+            search: parse_hex_color(&p.search),
+            accent: parse_hex_color(&p.accent),
+        }
+    }
+}
+
+fn parse_hex_color(value: &str) -> Color {
+    let hex = value.trim_start_matches('#');
+    if hex.len() == 6 {
+        if let (Ok(r), Ok(g), Ok(b)) = (
+            u8::from_str_radix(&hex[0..2], 16),
+            u8::from_str_radix(&hex[2..4], 16),
+            u8::from_str_radix(&hex[4..6], 16),
+        ) {
+            return Color::Rgb(r, g, b);
+        }
+    }
+    Color::Reset
+}
+
+/// Load `themes.yaml` from the app config directory, if present.
+fn load_theme_config() -> anyhow::Result<Option<ThemeConfig>> {
+    let Some(config_dir) = crate::session::get_claude_config_dir().or_else(dirs::home_dir) else {
+        return Ok(None);
+    };
+
+    let path = config_dir.join("themes.yaml");
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    Ok(Some(serde_yaml::from_str(&content)?))
 }
@@ -0,0 +1,165 @@
+//! Ordered-subsequence fuzzy matching, shared by the CLI's session/group
+//! resolvers and the TUI command palette.
+
+/// Score `candidate` against `query` as an ordered subsequence match.
+/// Returns `None` when `query` isn't a subsequence of `candidate` at all.
+/// Higher scores are better matches; consecutive runs and matches at
+/// word/path boundaries (after `/`, `-`, `_`, `.`, a space, or a
+/// lower->upper camelCase transition, and the very start of the string)
+/// are rewarded, gaps between matched characters are penalized.
+pub fn score(query: &str, candidate: &str) -> Option<i64> {
+    match_positions(query, candidate).map(|(score, _)| score)
+}
+
+fn is_boundary(chars: &[char], idx: usize) -> bool {
+    if idx == 0 {
+        return true;
+    }
+    let prev = chars[idx - 1];
+    let cur = chars[idx];
+    matches!(prev, '/' | '-' | '_' | '.' | ' ')
+        || (prev.is_lowercase() && cur.is_uppercase())
+}
+
+/// Like [`score`], but also returns the char indices in `candidate` that
+/// matched a query character, for highlighting in rendered output.
+pub fn match_positions(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let chars: Vec<char> = candidate.chars().collect();
+    let lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut qi = 0;
+    let mut total: i64 = 0;
+    let mut last_match: Option<usize> = None;
+    let mut positions = Vec::new();
+
+    for (ci, &c) in lower.iter().enumerate() {
+        if qi >= query.len() {
+            break;
+        }
+        if c != query[qi] {
+            continue;
+        }
+
+        let mut points: i64 = 1;
+
+        if is_boundary(&chars, ci) {
+            points += 8;
+        }
+
+        if let Some(last) = last_match {
+            if ci == last + 1 {
+                points += 5;
+            } else {
+                points -= (ci - last - 1) as i64;
+            }
+        }
+
+        total += points;
+        last_match = Some(ci);
+        positions.push(ci);
+        qi += 1;
+    }
+
+    if qi == query.len() {
+        Some((total, positions))
+    } else {
+        None
+    }
+}
+
+/// A candidate paired with its fuzzy score, as produced by [`rank`].
+#[derive(Debug, Clone, Copy)]
+pub struct Ranked {
+    pub index: usize,
+    pub score: i64,
+}
+
+/// Score every candidate against `query`, keeping only subsequence matches,
+/// sorted by descending score.
+pub fn rank(query: &str, candidates: &[String]) -> Vec<Ranked> {
+    let mut ranked: Vec<Ranked> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| {
+            score(query, candidate).map(|score| Ranked { index, score })
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.score.cmp(&a.score));
+    ranked
+}
+
+/// Whether the top-ranked candidate clearly dominates the runner-up, and so
+/// can be auto-accepted without prompting.
+pub fn top_dominates(ranked: &[Ranked]) -> bool {
+    match ranked {
+        [] => false,
+        [_] => true,
+        [top, second, ..] => top.score >= second.score + second.score.max(1) / 2 + 5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_subsequence_scores_none() {
+        assert_eq!(score("xyz", "abc"), None);
+    }
+
+    #[test]
+    fn empty_query_matches_everything_with_zero_score() {
+        assert_eq!(score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn boundary_match_scores_higher_than_mid_word_match() {
+        // "f" lands on a word-boundary char in "my_file" (right after '_')
+        // but mid-word in "wolf".
+        let boundary = score("f", "my_file").unwrap();
+        let mid_word = score("f", "wolf").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn consecutive_matches_score_higher_than_scattered_ones() {
+        let consecutive = score("ab", "ab-long-tail").unwrap();
+        let scattered = score("ab", "a-long-b-tail").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn top_dominates_is_true_for_a_single_candidate() {
+        let ranked = [Ranked { index: 0, score: 3 }];
+        assert!(top_dominates(&ranked));
+    }
+
+    #[test]
+    fn top_dominates_is_false_with_empty_candidates() {
+        assert!(!top_dominates(&[]));
+    }
+
+    #[test]
+    fn top_dominates_is_false_for_a_close_runner_up() {
+        let ranked = [
+            Ranked { index: 0, score: 10 },
+            Ranked { index: 1, score: 9 },
+        ];
+        assert!(!top_dominates(&ranked));
+    }
+
+    #[test]
+    fn top_dominates_is_true_when_clearly_ahead() {
+        let ranked = [
+            Ranked { index: 0, score: 20 },
+            Ranked { index: 1, score: 2 },
+        ];
+        assert!(top_dominates(&ranked));
+    }
+}
@@ -0,0 +1,91 @@
+//! Levenshtein-distance "did you mean?" suggestions, in the spirit of
+//! cargo's `lev_distance` helper for typo'd subcommands.
+
+/// Standard edit-distance DP over chars, using a two-row rolling buffer:
+/// O(n·m) time, O(min(n,m)) space.
+pub fn distance(a: &str, b: &str) -> usize {
+    let (shorter, longer) = if a.chars().count() <= b.chars().count() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    let shorter: Vec<char> = shorter.chars().collect();
+    let longer: Vec<char> = longer.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=shorter.len()).collect();
+    let mut curr = vec![0usize; shorter.len() + 1];
+
+    for (i, &lc) in longer.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &sc) in shorter.iter().enumerate() {
+            let cost = if lc == sc { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[shorter.len()]
+}
+
+/// Return the single closest candidate to `input`, if its edit distance is
+/// within `max(1, input.len() / 3)`. Otherwise `None`, so the caller can
+/// fall back to listing everything.
+pub fn suggest_closest<'a, I, S>(input: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a S>,
+    S: AsRef<str> + 'a,
+{
+    let threshold = (input.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|c| c.as_ref())
+        .map(|c| (c, distance(input, c)))
+        .filter(|(_, dist)| *dist <= threshold)
+        .min_by_key(|(_, dist)| *dist)
+        .map(|(c, _)| c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_of_equal_strings_is_zero() {
+        assert_eq!(distance("claude", "claude"), 0);
+    }
+
+    #[test]
+    fn distance_handles_empty_strings() {
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(distance("", "abc"), 3);
+        assert_eq!(distance("abc", ""), 3);
+    }
+
+    #[test]
+    fn distance_of_asymmetric_lengths() {
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("sitting", "kitten"), 3);
+    }
+
+    #[test]
+    fn suggest_closest_accepts_match_at_threshold_boundary() {
+        // "exa" has len 3, threshold = max(1, 3/3) = 1.
+        let candidates = ["exo".to_string()];
+        assert_eq!(suggest_closest("exa", &candidates), Some("exo"));
+    }
+
+    #[test]
+    fn suggest_closest_rejects_match_over_threshold() {
+        // "exa" has len 3, threshold = 1, but "zzz" is distance 3 away.
+        let candidates = ["zzz".to_string()];
+        assert_eq!(suggest_closest("exa", &candidates), None);
+    }
+
+    #[test]
+    fn suggest_closest_picks_the_nearest_of_several_candidates() {
+        let candidates = ["exec".to_string(), "exa".to_string(), "extra".to_string()];
+        assert_eq!(suggest_closest("exo", &candidates), Some("exa"));
+    }
+}
@@ -0,0 +1,5 @@
+//! Small helpers shared across the CLI and TUI that don't belong to any
+//! one subsystem.
+
+pub mod fuzzy;
+pub mod lev;